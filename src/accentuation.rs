@@ -0,0 +1,230 @@
+use crate::{categorise, is_dipthong, syllables, Accent, Breathing};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Locate the word accent in `word` and report which `Accent` it is.
+//
+// Runs `syllables` to split the word, then scans each syllable's
+// graphemes through `categorise` until one carries a non-`Unaccented`
+// value. The position is counted back from the ultima (0 = ultima,
+// 1 = penult, 2 = antepenult), matching how Greek grammars describe
+// oxytone/paroxytone/proparoxytone and perispomenon/properispomenon words.
+pub fn accentuation(word: &str) -> Option<(usize, Accent)> {
+    let sylls = syllables(word);
+    let len = sylls.len();
+    for (i, syllable) in sylls.iter().enumerate() {
+        for g in syllable.graphemes(true) {
+            let accent = categorise(g).4;
+            if accent != Accent::Unaccented {
+                return Some((len - 1 - i, accent));
+            }
+        }
+    }
+    None
+}
+
+// The accent kind `add_accent` can place. Unlike `Accent`, there is no
+// `Grave` or `Unaccented` variant: those aren't something callers place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccentKind {
+    Acute,
+    Circumflex,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AccentError {
+    // `position` is farther back than the antepenult, or the word doesn't
+    // have that many syllables.
+    TooFarBack,
+    // A circumflex was requested on a vowel that is always short.
+    CircumflexOnShortOnlyVowel,
+    // The target syllable has no vowel to carry an accent.
+    NoAccentableVowel,
+}
+
+// Place `kind` on the syllable counted `position` back from the ultima
+// (0 = ultima).
+//
+// Finds the target syllable with `syllables` and its accent-bearing vowel
+// with `categorise` (the second vowel of a diphthong), then swaps in the
+// precomposed accented form, preserving any existing breathing. Rejects
+// combinations Greek accentuation forbids: a circumflex on a vowel that
+// is always short, or an accent placed farther back than the antepenult.
+// No other placement rule is checked: the length of the ultima is not
+// consulted, and a circumflex on the antepenult is accepted.
+pub fn add_accent(word: &str, position: usize, kind: AccentKind) -> Result<String, AccentError> {
+    if position > 2 {
+        return Err(AccentError::TooFarBack);
+    }
+
+    let sylls = syllables(word);
+    let len = sylls.len();
+    if position >= len {
+        return Err(AccentError::TooFarBack);
+    }
+    let target = len - 1 - position;
+    let syllable = sylls[target];
+
+    let mut graphemes = syllable.grapheme_indices(true);
+    let mut start = None;
+    let mut end = 0;
+    let mut base = 0 as char;
+    let mut breathing = Breathing::None;
+    let mut diaeresis = false;
+
+    for (idx, g) in graphemes.by_ref() {
+        let (b, _, vowel, gb, _, gd) = categorise(g);
+        if vowel {
+            base = b;
+            breathing = gb;
+            diaeresis = gd;
+            start = Some(idx);
+            end = idx + g.len();
+            break;
+        }
+    }
+    let Some(mut start) = start else {
+        return Err(AccentError::NoAccentableVowel);
+    };
+
+    // The accent lands on the second vowel of a diphthong.
+    if let Some((idx, g)) = graphemes.next() {
+        let (b2, _, vowel, gb2, _, gd2) = categorise(g);
+        if vowel && is_dipthong(base, b2) {
+            start = end;
+            end = idx + g.len();
+            base = b2;
+            breathing = gb2;
+            diaeresis = gd2;
+        }
+    }
+
+    let replacement =
+        accented_form(base, breathing, kind, diaeresis).ok_or(AccentError::CircumflexOnShortOnlyVowel)?;
+
+    let prefix_len: usize = sylls[..target].iter().map(|s| s.len()).sum();
+    let mut result = String::with_capacity(word.len());
+    result.push_str(&word[..prefix_len + start]);
+    result.push_str(replacement);
+    result.push_str(&word[prefix_len + end..]);
+    Ok(result)
+}
+
+fn accented_form(base: char, breathing: Breathing, kind: AccentKind, diaeresis: bool) -> Option<&'static str> {
+    if diaeresis {
+        return match (base, kind) {
+            ('ι', AccentKind::Acute) => Some("ΐ"),
+            ('υ', AccentKind::Acute) => Some("ΰ"),
+            _ => None,
+        };
+    }
+    match (base, breathing, kind) {
+        ('α', Breathing::None, AccentKind::Acute) => Some("ά"),
+        ('α', Breathing::Smooth, AccentKind::Acute) => Some("ἄ"),
+        ('α', Breathing::Rough, AccentKind::Acute) => Some("ἅ"),
+        ('α', Breathing::None, AccentKind::Circumflex) => Some("ᾶ"),
+        ('α', Breathing::Smooth, AccentKind::Circumflex) => Some("ἆ"),
+        ('α', Breathing::Rough, AccentKind::Circumflex) => Some("ἇ"),
+
+        ('ε', Breathing::None, AccentKind::Acute) => Some("έ"),
+        ('ε', Breathing::Smooth, AccentKind::Acute) => Some("ἔ"),
+        ('ε', Breathing::Rough, AccentKind::Acute) => Some("ἕ"),
+
+        ('η', Breathing::None, AccentKind::Acute) => Some("ή"),
+        ('η', Breathing::Smooth, AccentKind::Acute) => Some("ἤ"),
+        ('η', Breathing::Rough, AccentKind::Acute) => Some("ἥ"),
+        ('η', Breathing::None, AccentKind::Circumflex) => Some("ῆ"),
+        ('η', Breathing::Smooth, AccentKind::Circumflex) => Some("ἦ"),
+        ('η', Breathing::Rough, AccentKind::Circumflex) => Some("ἧ"),
+
+        ('ι', Breathing::None, AccentKind::Acute) => Some("ί"),
+        ('ι', Breathing::Smooth, AccentKind::Acute) => Some("ἴ"),
+        ('ι', Breathing::Rough, AccentKind::Acute) => Some("ἵ"),
+        ('ι', Breathing::None, AccentKind::Circumflex) => Some("ῖ"),
+        ('ι', Breathing::Smooth, AccentKind::Circumflex) => Some("ἶ"),
+        ('ι', Breathing::Rough, AccentKind::Circumflex) => Some("ἷ"),
+
+        ('ο', Breathing::None, AccentKind::Acute) => Some("ό"),
+        ('ο', Breathing::Smooth, AccentKind::Acute) => Some("ὄ"),
+        ('ο', Breathing::Rough, AccentKind::Acute) => Some("ὅ"),
+
+        ('υ', Breathing::None, AccentKind::Acute) => Some("ύ"),
+        ('υ', Breathing::Smooth, AccentKind::Acute) => Some("ὔ"),
+        ('υ', Breathing::Rough, AccentKind::Acute) => Some("ὕ"),
+        ('υ', Breathing::None, AccentKind::Circumflex) => Some("ῦ"),
+        ('υ', Breathing::Smooth, AccentKind::Circumflex) => Some("ὖ"),
+        ('υ', Breathing::Rough, AccentKind::Circumflex) => Some("ὗ"),
+
+        ('ω', Breathing::None, AccentKind::Acute) => Some("ώ"),
+        ('ω', Breathing::Smooth, AccentKind::Acute) => Some("ὤ"),
+        ('ω', Breathing::Rough, AccentKind::Acute) => Some("ὥ"),
+        ('ω', Breathing::None, AccentKind::Circumflex) => Some("ῶ"),
+        ('ω', Breathing::Smooth, AccentKind::Circumflex) => Some("ὦ"),
+        ('ω', Breathing::Rough, AccentKind::Circumflex) => Some("ὧ"),
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oxytone() {
+        assert_eq!(accentuation("γυναικός"), Some((0, Accent::Acute)));
+    }
+
+    #[test]
+    fn test_paroxytone() {
+        assert_eq!(accentuation("χρίστος"), Some((1, Accent::Acute)));
+    }
+
+    #[test]
+    fn test_properispomenon() {
+        assert_eq!(accentuation("μῶρος"), Some((1, Accent::Circumflex)));
+    }
+
+    #[test]
+    fn test_unaccented() {
+        assert_eq!(accentuation("σσσ"), None);
+    }
+
+    #[test]
+    fn test_add_accent_oxytone() {
+        assert_eq!(add_accent("λογος", 0, AccentKind::Acute), Ok("λογός".to_string()));
+    }
+
+    #[test]
+    fn test_add_accent_preserves_breathing() {
+        assert_eq!(add_accent("ανθρωπος", 2, AccentKind::Acute), Ok("άνθρωπος".to_string()));
+        assert_eq!(add_accent("ἀνθρωπος", 2, AccentKind::Acute), Ok("ἄνθρωπος".to_string()));
+    }
+
+    #[test]
+    fn test_add_accent_on_diphthong() {
+        // Breathing on a diphthong is marked over its second vowel.
+        assert_eq!(add_accent("αἰτιος", 2, AccentKind::Acute), Ok("αἴτιος".to_string()));
+    }
+
+    #[test]
+    fn test_add_accent_diaeresis_round_trips() {
+        // ΐ/ΰ must be a grapheme `categorise` (and so `accentuation`)
+        // recognizes, or the accent placed here can never be read back.
+        let accented = add_accent("μωϋσης", 1, AccentKind::Acute).unwrap();
+        assert_eq!(accented, "μωΰσης");
+        assert_eq!(accentuation(&accented), Some((1, Accent::Acute)));
+    }
+
+    #[test]
+    fn test_add_accent_rejects_circumflex_on_short_vowel() {
+        assert_eq!(
+            add_accent("ανθρωπε", 0, AccentKind::Circumflex),
+            Err(AccentError::CircumflexOnShortOnlyVowel)
+        );
+    }
+
+    #[test]
+    fn test_add_accent_rejects_too_far_back() {
+        assert_eq!(add_accent("λογος", 3, AccentKind::Acute), Err(AccentError::TooFarBack));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{categorise, is_dipthong, syllables, Accent, Breathing};
+use unicode_segmentation::UnicodeSegmentation;
+
+// A syllable decomposed into its onset, nucleus, and coda, alongside the
+// `Accent`/`Breathing` already decoded from its accent-bearing vowel.
+#[derive(Debug, PartialEq)]
+pub struct SyllableInfo<'a> {
+    pub text: &'a str,
+    pub onset: &'a str,
+    pub nucleus: &'a str,
+    pub coda: &'a str,
+    pub is_diphthong: bool,
+    pub accent: Accent,
+    pub breathing: Breathing,
+}
+
+// Split `word` into syllables like `syllables` does, but keep the
+// onset/nucleus/coda decomposition and per-syllable `Accent`/`Breathing`
+// that a bare `&str` slice discards.
+pub fn analyze(word: &str) -> Vec<SyllableInfo<'_>> {
+    syllables(word).into_iter().map(decompose).collect()
+}
+
+fn decompose(syllable: &str) -> SyllableInfo<'_> {
+    let mut graphemes = syllable.grapheme_indices(true);
+    let mut nucleus_start = syllable.len();
+    let mut nucleus_end = syllable.len();
+    let mut first_base = 0 as char;
+    let mut accent = Accent::Unaccented;
+    let mut breathing = Breathing::None;
+
+    for (idx, g) in graphemes.by_ref() {
+        let (base, _, vowel, gb, ga, _) = categorise(g);
+        if vowel {
+            first_base = base;
+            nucleus_start = idx;
+            nucleus_end = idx + g.len();
+            accent = ga;
+            breathing = gb;
+            break;
+        }
+    }
+
+    let mut is_diphthong = false;
+    if let Some((idx, g)) = graphemes.next() {
+        let (base, _, vowel, gb, ga, diaeresis) = categorise(g);
+        if vowel && !diaeresis && is_dipthong(first_base, base) {
+            nucleus_end = idx + g.len();
+            is_diphthong = true;
+            accent = ga;
+            breathing = gb;
+        }
+    }
+
+    SyllableInfo {
+        text: syllable,
+        onset: &syllable[..nucleus_start],
+        nucleus: &syllable[nucleus_start..nucleus_end],
+        coda: &syllable[nucleus_end..],
+        is_diphthong,
+        accent,
+        breathing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_onset_nucleus_coda() {
+        let info = &analyze("χρίστος")[0];
+        assert_eq!(info.text, "χρί");
+        assert_eq!(info.onset, "χρ");
+        assert_eq!(info.nucleus, "ί");
+        assert_eq!(info.coda, "");
+        assert!(!info.is_diphthong);
+        assert_eq!(info.accent, Accent::Acute);
+
+        let info = &analyze("χρίστος")[1];
+        assert_eq!(info.onset, "στ");
+        assert_eq!(info.nucleus, "ο");
+        assert_eq!(info.coda, "ς");
+    }
+
+    #[test]
+    fn test_diphthong_nucleus_and_breathing() {
+        let info = &analyze("αἵτινες")[0];
+        assert_eq!(info.nucleus, "αἵ");
+        assert!(info.is_diphthong);
+        assert_eq!(info.accent, Accent::Acute);
+        assert_eq!(info.breathing, Breathing::Rough);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{accentuation, categorise, is_dipthong, syllables, Accent};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Length of a syllable nucleus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VowelLength {
+    Long,
+    Short,
+    Ambiguous,
+}
+
+// Classify the length of a nucleus: `η`/`ω` and genuine diphthongs are
+// always long, `ε`/`ο` always short, and the dichrona `α`/`ι`/`υ` are
+// ambiguous without further context.
+pub fn vowel_length(nucleus_first: char, nucleus_second: Option<char>) -> VowelLength {
+    if nucleus_second.is_some() {
+        return VowelLength::Long;
+    }
+    match nucleus_first {
+        'η' | 'ω' => VowelLength::Long,
+        'ε' | 'ο' => VowelLength::Short,
+        _ => VowelLength::Ambiguous,
+    }
+}
+
+// Insert a combining macron (long) or breve (short) onto the dichrona
+// (`α`, `ι`, `υ`) whose length the word's accent already forces: a
+// circumflex can only sit on a long nucleus, a properispomenon requires a
+// short ultima, and an acute on the antepenult requires a short ultima.
+// Genuinely undetermined dichrona are left unmarked.
+pub fn mark_implied_length(word: &str) -> String {
+    let sylls = syllables(word);
+    let len = sylls.len();
+    let nuclei: Vec<(char, Option<char>, usize, usize)> = sylls.iter().map(|s| nucleus(s)).collect();
+    let mut lengths: Vec<VowelLength> = nuclei
+        .iter()
+        .map(|(first, second, _, _)| vowel_length(*first, *second))
+        .collect();
+
+    if let Some((position, accent)) = accentuation(word) {
+        let accent_idx = len - 1 - position;
+        if accent == Accent::Circumflex && lengths[accent_idx] == VowelLength::Ambiguous {
+            lengths[accent_idx] = VowelLength::Long;
+        }
+        let forces_short_ultima =
+            (accent == Accent::Circumflex && position == 1) || (accent == Accent::Acute && position == 2);
+        if forces_short_ultima {
+            let ultima_idx = len - 1;
+            if lengths[ultima_idx] == VowelLength::Ambiguous {
+                lengths[ultima_idx] = VowelLength::Short;
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for (i, syllable) in sylls.iter().enumerate() {
+        let (first, second, _, end) = nuclei[i];
+        let is_dichronon = second.is_none() && matches!(first, 'α' | 'ι' | 'υ');
+        out.push_str(&syllable[..end]);
+        if is_dichronon {
+            match lengths[i] {
+                VowelLength::Long => out.push('\u{0304}'),
+                VowelLength::Short => out.push('\u{0306}'),
+                VowelLength::Ambiguous => {}
+            }
+        }
+        out.push_str(&syllable[end..]);
+    }
+    out
+}
+
+// Base char, optional second vowel (for a diphthong), and the byte range
+// of the nucleus within `syllable`.
+fn nucleus(syllable: &str) -> (char, Option<char>, usize, usize) {
+    let mut graphemes = syllable.grapheme_indices(true);
+    let mut first = 0 as char;
+    let mut start = syllable.len();
+    let mut end = syllable.len();
+    for (idx, g) in graphemes.by_ref() {
+        let (base, _, vowel, _, _, _) = categorise(g);
+        if vowel {
+            first = base;
+            start = idx;
+            end = idx + g.len();
+            break;
+        }
+    }
+    if let Some((idx, g)) = graphemes.next() {
+        let (base, _, vowel, _, _, diaeresis) = categorise(g);
+        if vowel && !diaeresis && is_dipthong(first, base) {
+            return (first, Some(base), start, idx + g.len());
+        }
+    }
+    (first, None, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vowel_length_classification() {
+        assert_eq!(vowel_length('η', None), VowelLength::Long);
+        assert_eq!(vowel_length('ε', None), VowelLength::Short);
+        assert_eq!(vowel_length('α', None), VowelLength::Ambiguous);
+        assert_eq!(vowel_length('α', Some('ι')), VowelLength::Long);
+    }
+
+    #[test]
+    fn test_circumflex_forces_long_nucleus() {
+        // πᾶς: the circumflex forces the dichronon α long, marked with a macron.
+        assert_eq!(mark_implied_length("πᾶς"), "πᾶ\u{0304}ς");
+        // μῶρος: the circumflexed ω is long anyway, so nothing is marked.
+        assert_eq!(mark_implied_length("μῶρος"), "μῶρος");
+        // ἀγαθά: acute on the ultima, no constraint to propagate.
+        assert_eq!(mark_implied_length("ἀγαθά"), "ἀγαθά");
+    }
+
+    #[test]
+    fn test_properispomenon_forces_short_ultima() {
+        // A circumflex on the penult (properispomenon) forces a short
+        // ultima, marking the ambiguous α there with a breve.
+        assert_eq!(mark_implied_length("δῶρα"), "δῶρα\u{0306}");
+    }
+}
@@ -2,6 +2,21 @@ use unicode_segmentation::UnicodeSegmentation;
 use Accent::*;
 use Breathing::*;
 
+mod pronounce;
+pub use pronounce::{pronounce, Era};
+
+mod transliterate;
+pub use transliterate::transliterate;
+
+mod accentuation;
+pub use accentuation::{accentuation, add_accent, AccentError, AccentKind};
+
+mod length;
+pub use length::{mark_implied_length, vowel_length, VowelLength};
+
+mod analyze;
+pub use analyze::{analyze, SyllableInfo};
+
 // Split a sequence of unicode Greek characters into syllables.
 // Characters can be accented. Characters can be composed as NFD or NFC.
 #[inline]
@@ -144,7 +159,7 @@ fn joinable_consonant(a: char, b: char) -> bool {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Accent {
     Acute,
     Circumflex,
@@ -152,7 +167,7 @@ pub enum Accent {
     Unaccented,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Breathing {
     Rough,
     Smooth,
@@ -189,17 +204,32 @@ fn categorise(c: &str) -> (char, &'static str, bool, Breathing, Accent, bool) {
         "Ϊ" | "ϊ" => {
             return ('ι', "i", true, None, Unaccented, true);
         }
+        "ΐ" | "ι\u{308}\u{301}" | "ι\u{301}\u{308}" | "Ι\u{308}\u{301}" | "Ι\u{301}\u{308}" => {
+            return ('ι', "i", true, None, Acute, true);
+        }
+        "ΰ" | "υ\u{308}\u{301}" | "υ\u{301}\u{308}" | "Υ\u{308}\u{301}" | "Υ\u{301}\u{308}" => {
+            return ('υ', "u", true, None, Acute, true);
+        }
+        "ι\u{308}\u{300}" | "ι\u{300}\u{308}" | "Ι\u{308}\u{300}" | "Ι\u{300}\u{308}" => {
+            return ('ι', "i", true, None, Grave, true);
+        }
+        "υ\u{308}\u{300}" | "υ\u{300}\u{308}" | "Υ\u{308}\u{300}" | "Υ\u{300}\u{308}" => {
+            return ('υ', "u", true, None, Grave, true);
+        }
         "ᾶ" => {
-            return ('ᾶ', "a", true, None, Circumflex, false);
+            return ('α', "a", true, None, Circumflex, false);
         }
         "ῆ" => {
-            return ('ῆ', "e", true, None, Circumflex, false);
+            return ('η', "e", true, None, Circumflex, false);
         }
         "ῖ" => {
-            return ('ῖ', "i", true, None, Circumflex, false);
+            return ('ι', "i", true, None, Circumflex, false);
+        }
+        "ῦ" => {
+            return ('υ', "u", true, None, Circumflex, false);
         }
         "ῶ" => {
-            return ('ῶ', "o", true, None, Circumflex, false);
+            return ('ω', "o", true, None, Circumflex, false);
         }
         "Ά" | "Α\u{301}" | "ά" | "α\u{301}" => {
             return ('α', "a", true, Smooth, Acute, false);
@@ -277,7 +307,7 @@ fn categorise(c: &str) -> (char, &'static str, bool, Breathing, Accent, bool) {
             return ('ι', "i", true, Rough, Unaccented, false);
         }
         "Ὁ" | "ὁ" => {
-            return ('ο', "i", true, Rough, Unaccented, false);
+            return ('ο', "o", true, Rough, Unaccented, false);
         }
         "Ὑ" | "ὑ" => {
             return ('υ', "u", true, Rough, Unaccented, false);
@@ -369,6 +399,36 @@ fn categorise(c: &str) -> (char, &'static str, bool, Breathing, Accent, bool) {
         "Ὣ" | "ὣ" => {
             return ('ω', "o", true, Rough, Grave, false);
         }
+        "Ἆ" | "ἆ" => {
+            return ('α', "a", true, Smooth, Circumflex, false);
+        }
+        "Ἦ" | "ἦ" => {
+            return ('η', "e", true, Smooth, Circumflex, false);
+        }
+        "Ἶ" | "ἶ" => {
+            return ('ι', "i", true, Smooth, Circumflex, false);
+        }
+        "ὖ" => {
+            return ('υ', "u", true, Smooth, Circumflex, false);
+        }
+        "Ὦ" | "ὦ" => {
+            return ('ω', "o", true, Smooth, Circumflex, false);
+        }
+        "Ἇ" | "ἇ" => {
+            return ('α', "a", true, Rough, Circumflex, false);
+        }
+        "Ἧ" | "ἧ" => {
+            return ('η', "e", true, Rough, Circumflex, false);
+        }
+        "Ἷ" | "ἷ" => {
+            return ('ι', "i", true, Rough, Circumflex, false);
+        }
+        "Ὗ" | "ὗ" => {
+            return ('υ', "u", true, Rough, Circumflex, false);
+        }
+        "Ὧ" | "ὧ" => {
+            return ('ω', "o", true, Rough, Circumflex, false);
+        }
         "Β" | "β" => {
             return ('β', "b", false, None, Unaccented, false);
         }
@@ -405,6 +465,9 @@ fn categorise(c: &str) -> (char, &'static str, bool, Breathing, Accent, bool) {
         "Ρ" | "ρ" => {
             return ('ρ', "r", false, None, Unaccented, false);
         }
+        "Ῥ" | "ῥ" => {
+            return ('ρ', "r", false, Rough, Unaccented, false);
+        }
         "Σ" | "σ" | "ς" => {
             return ('σ', "s", false, None, Unaccented, false);
         }
@@ -0,0 +1,422 @@
+use crate::{categorise, is_dipthong, syllables, Accent, Breathing};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Historical stage of Greek whose sound system `pronounce` reconstructs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Era {
+    Classical,
+    Koine,
+    Modern,
+}
+
+struct Unit {
+    base: char,
+    vowel: bool,
+    breathing: Breathing,
+    accent: Accent,
+    diaeresis: bool,
+    start: usize,
+}
+
+// Reconstruct the IPA pronunciation of `word` for the given `era`, reusing
+// the attributes `categorise` decodes for each letter and the syllable
+// boundaries `syllables` finds for placing the Modern stress mark.
+//
+// Nasal+stop clusters (μπ/ντ/γκ) and the gamma-nasal sequences (γγ/γχ/γξ)
+// are resolved jointly across both their graphemes, since `syllables`
+// would otherwise split them at a point the era transform must not see.
+pub fn pronounce(word: &str, era: Era) -> String {
+    let units = collect_units(word);
+    let stress_start = if era == Era::Modern {
+        stressed_syllable_start(word)
+    } else {
+        None
+    };
+
+    let mut out = String::new();
+    let mut i = 0;
+    while i < units.len() {
+        let u = &units[i];
+
+        if !u.vowel {
+            if let Some((ipa, consumed)) = nasal_cluster(&units, i, era, u.start == 0) {
+                // The cluster's two graphemes can straddle the stressed
+                // syllable's onset, so every unit it consumes must be
+                // checked, not just the first.
+                if (0..consumed).any(|o| stress_start == Some(units[i + o].start)) {
+                    out.push('ˈ');
+                }
+                out.push_str(&ipa);
+                i += consumed;
+                continue;
+            }
+            if stress_start == Some(u.start) {
+                out.push('ˈ');
+            }
+            out.push_str(consonant_ipa(u.base, next_vowel_is_front(&units, i), era));
+            i += 1;
+            continue;
+        }
+
+        if stress_start == Some(u.start) {
+            out.push('ˈ');
+        }
+
+        // Breathing on a diphthong is marked over its second vowel, so
+        // both graphemes must be checked before deciding whether to
+        // prepend "h".
+        let second = units.get(i + 1).filter(|n| n.vowel && !n.diaeresis && is_dipthong(u.base, n.base));
+        if era != Era::Modern {
+            let rough =
+                u.breathing == Breathing::Rough || second.is_some_and(|n| n.breathing == Breathing::Rough);
+            if rough {
+                out.push_str(breathing_ipa(Breathing::Rough).unwrap());
+            }
+        }
+        if let Some((ipa, consumed)) = diphthong(&units, i, era) {
+            out.push_str(&ipa);
+            // The diphthong's accent is carried by its second vowel.
+            out.push_str(accent_ipa(second.map_or(u.accent, |n| n.accent), era));
+            i += consumed;
+            continue;
+        }
+        out.push_str(vowel_ipa(u.base, era));
+        out.push_str(accent_ipa(u.accent, era));
+        i += 1;
+    }
+    out
+}
+
+fn collect_units(word: &str) -> Vec<Unit> {
+    let mut units = Vec::new();
+    for (start, element) in word.grapheme_indices(true) {
+        let (base, _, vowel, breathing, accent, diaeresis) = categorise(element);
+        if base == 0 as char {
+            continue;
+        }
+        units.push(Unit { base, vowel, breathing, accent, diaeresis, start });
+    }
+    units
+}
+
+// Byte offset of the syllable carrying the word's written accent, used to
+// site the Modern stress mark at that syllable's onset.
+fn stressed_syllable_start(word: &str) -> Option<usize> {
+    let mut offset = 0usize;
+    for syllable in syllables(word) {
+        if syllable
+            .graphemes(true)
+            .any(|g| categorise(g).4 != Accent::Unaccented)
+        {
+            return Some(offset);
+        }
+        offset += syllable.len();
+    }
+    None
+}
+
+fn breathing_ipa(breathing: Breathing) -> Option<&'static str> {
+    match breathing {
+        Breathing::Rough => Some("h"),
+        _ => None,
+    }
+}
+
+fn accent_ipa(accent: Accent, era: Era) -> &'static str {
+    if era == Era::Modern {
+        return "";
+    }
+    match accent {
+        Accent::Acute => "\u{0301}",
+        Accent::Circumflex => "\u{0302}",
+        _ => "",
+    }
+}
+
+fn next_vowel_is_front(units: &[Unit], i: usize) -> bool {
+    match units.get(i + 1) {
+        Some(u) if u.vowel => matches!(u.base, 'ε' | 'η' | 'ι' | 'υ'),
+        _ => false,
+    }
+}
+
+fn is_voiced_consonant(base: char) -> bool {
+    matches!(base, 'β' | 'γ' | 'δ' | 'ζ' | 'λ' | 'μ' | 'ν' | 'ρ')
+}
+
+// Nasal+stop sequences (μπ/ντ/γκ) and gamma-nasal sequences (γγ/γχ/γξ) are
+// decided as a single span instead of as two independent consonants.
+fn nasal_cluster(units: &[Unit], i: usize, era: Era, is_word_initial: bool) -> Option<(String, usize)> {
+    let next = units.get(i + 1)?;
+    if next.vowel {
+        return None;
+    }
+    let cur = &units[i];
+    match (cur.base, next.base) {
+        ('γ', 'κ') => {
+            let ipa = match era {
+                Era::Modern if is_word_initial => "ɡ".to_string(),
+                Era::Modern => "ŋɡ".to_string(),
+                _ => "ŋk".to_string(),
+            };
+            Some((ipa, 2))
+        }
+        ('γ', 'γ') | ('γ', 'χ') | ('γ', 'ξ') => {
+            Some((format!("ŋ{}", consonant_ipa(next.base, false, era)), 2))
+        }
+        ('μ', 'π') => {
+            let ipa = match era {
+                Era::Modern if is_word_initial => "b".to_string(),
+                Era::Modern => "mb".to_string(),
+                _ => "mp".to_string(),
+            };
+            Some((ipa, 2))
+        }
+        ('ν', 'τ') => {
+            let ipa = match era {
+                Era::Modern if is_word_initial => "d".to_string(),
+                Era::Modern => "nd".to_string(),
+                _ => "nt".to_string(),
+            };
+            Some((ipa, 2))
+        }
+        _ => None,
+    }
+}
+
+fn diphthong(units: &[Unit], i: usize, era: Era) -> Option<(String, usize)> {
+    let first = units[i].base;
+    let second_unit = units.get(i + 1)?;
+    if !second_unit.vowel || second_unit.diaeresis || !is_dipthong(first, second_unit.base) {
+        return None;
+    }
+    let second = second_unit.base;
+    let ipa = match era {
+        Era::Classical => classical_diphthong(first, second).to_string(),
+        Era::Koine => koine_diphthong(first, second).to_string(),
+        Era::Modern => modern_diphthong(units, i, first, second)?,
+    };
+    Some((ipa, 2))
+}
+
+fn classical_diphthong(first: char, second: char) -> &'static str {
+    match (first, second) {
+        ('α', 'ι') => "ai̯",
+        ('ε', 'ι') => "ei̯",
+        ('ο', 'ι') => "oi̯",
+        ('υ', 'ι') => "yi̯",
+        ('α', 'υ') => "au̯",
+        ('ε', 'υ') => "eu̯",
+        ('η', 'υ') => "ɛːu̯",
+        ('ο', 'υ') => "uː",
+        _ => "",
+    }
+}
+
+fn koine_diphthong(first: char, second: char) -> &'static str {
+    match (first, second) {
+        ('α', 'ι') => "e",
+        ('ε', 'ι') => "i",
+        ('ο', 'ι') => "y",
+        ('υ', 'ι') => "yi",
+        ('α', 'υ') => "au̯",
+        ('ε', 'υ') => "eu̯",
+        ('η', 'υ') => "iu̯",
+        ('ο', 'υ') => "u",
+        _ => "",
+    }
+}
+
+fn modern_diphthong(units: &[Unit], i: usize, first: char, second: char) -> Option<String> {
+    Some(match (first, second) {
+        ('α', 'ι') => "e".to_string(),
+        ('ε', 'ι') => "i".to_string(),
+        ('ο', 'ι') => "i".to_string(),
+        ('υ', 'ι') => "i".to_string(),
+        ('ο', 'υ') => "u".to_string(),
+        ('α', 'υ') => format!("a{}", modern_upsilon_glide(units, i + 2)),
+        ('ε', 'υ') => format!("e{}", modern_upsilon_glide(units, i + 2)),
+        ('η', 'υ') => format!("i{}", modern_upsilon_glide(units, i + 2)),
+        _ => return None,
+    })
+}
+
+// The υ of αυ/ευ/ηυ surfaces as /v/ before a voiced sound and /f/ before a
+// voiceless one (or word-finally).
+fn modern_upsilon_glide(units: &[Unit], next: usize) -> char {
+    match units.get(next) {
+        Some(u) if !u.vowel && !is_voiced_consonant(u.base) => 'f',
+        None => 'f',
+        _ => 'v',
+    }
+}
+
+fn vowel_ipa(base: char, era: Era) -> &'static str {
+    match era {
+        Era::Classical => match base {
+            'α' => "a",
+            'ε' => "e",
+            'η' => "ɛː",
+            'ι' => "i",
+            'ο' => "o",
+            'υ' => "y",
+            'ω' => "ɔː",
+            _ => "",
+        },
+        Era::Koine => match base {
+            'α' => "a",
+            'ε' => "e",
+            'η' => "e",
+            'ι' => "i",
+            'ο' => "o",
+            'υ' => "y",
+            'ω' => "o",
+            _ => "",
+        },
+        Era::Modern => match base {
+            'α' => "a",
+            'ε' => "e",
+            'η' => "i",
+            'ι' => "i",
+            'ο' => "o",
+            'υ' => "i",
+            'ω' => "o",
+            _ => "",
+        },
+    }
+}
+
+fn consonant_ipa(base: char, next_is_front: bool, era: Era) -> &'static str {
+    match era {
+        Era::Classical => match base {
+            'β' => "b",
+            'γ' => "ɡ",
+            'δ' => "d",
+            'ζ' => "zd",
+            'θ' => "tʰ",
+            'κ' => "k",
+            'λ' => "l",
+            'μ' => "m",
+            'ν' => "n",
+            'ξ' => "ks",
+            'π' => "p",
+            'ρ' => "r",
+            'σ' => "s",
+            'τ' => "t",
+            'φ' => "pʰ",
+            'χ' => "kʰ",
+            'ψ' => "ps",
+            _ => "",
+        },
+        Era::Koine => match base {
+            'β' => "v",
+            'γ' => "ɣ",
+            'δ' => "ð",
+            'ζ' => "z",
+            'θ' => "θ",
+            'κ' => "k",
+            'λ' => "l",
+            'μ' => "m",
+            'ν' => "n",
+            'ξ' => "ks",
+            'π' => "p",
+            'ρ' => "r",
+            'σ' => "s",
+            'τ' => "t",
+            'φ' => "f",
+            'χ' => "x",
+            'ψ' => "ps",
+            _ => "",
+        },
+        Era::Modern => match base {
+            'β' => "v",
+            'γ' => {
+                if next_is_front {
+                    "ʝ"
+                } else {
+                    "ɣ"
+                }
+            }
+            'δ' => "ð",
+            'ζ' => "z",
+            'θ' => "θ",
+            'κ' => "k",
+            'λ' => "l",
+            'μ' => "m",
+            'ν' => "n",
+            'ξ' => "ks",
+            'π' => "p",
+            'ρ' => "r",
+            'σ' => "s",
+            'τ' => "t",
+            'φ' => "f",
+            'χ' => {
+                if next_is_front {
+                    "ç"
+                } else {
+                    "x"
+                }
+            }
+            'ψ' => "ps",
+            _ => "",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classical_pronunciation() {
+        assert_eq!(pronounce("λόγος", Era::Classical), "lo\u{0301}ɡos");
+        assert_eq!(pronounce("ἄνθρωπος", Era::Classical), "a\u{0301}ntʰrɔːpos");
+    }
+
+    #[test]
+    fn test_modern_stress_and_iotacism() {
+        assert_eq!(pronounce("λόγος", Era::Modern), "ˈloɣos");
+        assert_eq!(pronounce("ἄνθρωπος", Era::Modern), "ˈanθropos");
+        assert_eq!(pronounce("ἀδελφή", Era::Modern), "aðelˈfi");
+    }
+
+    #[test]
+    fn test_nasal_and_gamma_clusters() {
+        assert_eq!(pronounce("ἄγγελος", Era::Modern), "ˈaŋɣelos");
+        assert_eq!(pronounce("ἄγγελος", Era::Koine), "a\u{0301}ŋɣelos");
+    }
+
+    #[test]
+    fn test_stress_survives_nasal_cluster_onset() {
+        // The stressed syllable starts on the stop of the ντ cluster, not
+        // the nasal before it.
+        assert_eq!(pronounce("ἀντί", Era::Modern), "aˈndi");
+    }
+
+    #[test]
+    fn test_diphthong_carries_accent() {
+        assert_eq!(pronounce("καί", Era::Classical), "kai̯\u{0301}");
+        assert_eq!(pronounce("τοῦτο", Era::Classical), "tuː\u{0302}to");
+    }
+
+    #[test]
+    fn test_circumflex_breathing_vowels() {
+        // ἶ/ὖ carry smooth breathing and a circumflex; previously
+        // `categorise` didn't recognize them and dropped the vowel.
+        assert_eq!(pronounce("οἶκος", Era::Classical), "oi̯\u{0302}kos");
+        assert_eq!(pronounce("εὖ", Era::Classical), "eu̯\u{0302}");
+    }
+
+    #[test]
+    fn test_diaeresis_vowel_and_accent_survive() {
+        // ΰ (υ with diaeresis and acute) used to be dropped entirely.
+        assert_eq!(pronounce("πραΰς", Era::Classical), "pray\u{0301}s");
+    }
+
+    #[test]
+    fn test_diaeresis_prevents_false_diphthong() {
+        // A diaeresis on the second vowel blocks the diphthong reading,
+        // just as `syllables`/`analyze` already treat it.
+        assert_eq!(pronounce("οϊ", Era::Classical), "oi");
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{categorise, is_dipthong, Accent, Breathing};
+use unicode_segmentation::UnicodeSegmentation;
+
+struct Unit {
+    base: char,
+    latin: &'static str,
+    vowel: bool,
+    breathing: Breathing,
+    accent: Accent,
+    upper: bool,
+    start: usize,
+}
+
+// Romanize `word` using the Latin equivalent `categorise` already carries
+// for every letter, layered with the contextual rules a straight
+// character-by-character mapping cannot express: gamma before a velar
+// transliterates as "n", rough breathing on an initial vowel, diphthong,
+// or rho prepends "h" (or yields "rh"), and diphthongs come out as their
+// two-letter Latin sequence. Case is preserved from the source word.
+//
+// When `keep_accents` is `true`, an acute Greek accent is rendered as a
+// combining acute and a circumflex as a combining macron (marking the
+// long vowel a circumflex always implies); otherwise accents are dropped.
+pub fn transliterate(word: &str, keep_accents: bool) -> String {
+    let units = collect_units(word);
+    let mut out = String::new();
+    let mut i = 0;
+    while i < units.len() {
+        let u = &units[i];
+
+        if u.vowel {
+            // Breathing on a diphthong is marked over its second vowel, so
+            // both graphemes must be checked before deciding whether to
+            // prepend "h".
+            let second = units.get(i + 1).filter(|n| n.vowel && is_dipthong(u.base, n.base));
+            let rough = u.breathing == Breathing::Rough
+                || second.is_some_and(|n| n.breathing == Breathing::Rough);
+            // A capitalized word's breathing lands on the "H", so the
+            // vowel itself is forced lowercase, matching the "Rh"/"rh"
+            // handling below for rough breathing on rho.
+            let word_initial_rough = rough && u.start == 0;
+            if word_initial_rough {
+                out.push(if u.upper { 'H' } else { 'h' });
+            }
+            out.push_str(&latin_str(u.latin, u.upper && !word_initial_rough));
+            if keep_accents {
+                push_accent(&mut out, u.accent);
+            }
+
+            if let Some(second) = second {
+                out.push_str(&latin_str(second.latin, second.upper));
+                if keep_accents {
+                    push_accent(&mut out, second.accent);
+                }
+                i += 2;
+                continue;
+            }
+            i += 1;
+            continue;
+        }
+
+        if u.base == 'ρ' && u.breathing == Breathing::Rough {
+            out.push_str(if u.upper { "Rh" } else { "rh" });
+            i += 1;
+            continue;
+        }
+
+        if u.base == 'γ' && matches!(units.get(i + 1).map(|n| n.base), Some('κ' | 'γ' | 'χ' | 'ξ')) {
+            out.push_str(if u.upper { "N" } else { "n" });
+            i += 1;
+            continue;
+        }
+
+        out.push_str(&latin_str(u.latin, u.upper));
+        i += 1;
+    }
+    out
+}
+
+fn collect_units(word: &str) -> Vec<Unit> {
+    let mut units = Vec::new();
+    for (start, element) in word.grapheme_indices(true) {
+        let (base, latin, vowel, breathing, accent, _) = categorise(element);
+        if base == 0 as char {
+            continue;
+        }
+        let upper = element.chars().next().is_some_and(char::is_uppercase);
+        units.push(Unit { base, latin, vowel, breathing, accent, upper, start });
+    }
+    units
+}
+
+fn latin_str(latin: &str, upper: bool) -> String {
+    if !upper {
+        return latin.to_string();
+    }
+    let mut chars = latin.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn push_accent(out: &mut String, accent: Accent) {
+    match accent {
+        Accent::Acute => out.push('\u{0301}'),
+        Accent::Circumflex => out.push('\u{0304}'),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_nasal_digraph() {
+        assert_eq!(transliterate("σάλπιγξ", false), "salpinx");
+        assert_eq!(transliterate("ὄγκος", false), "onkos");
+        assert_eq!(transliterate("Ἐγχειρίδιον", false), "Encheiridion");
+    }
+
+    #[test]
+    fn test_rough_breathing_and_diphthongs() {
+        assert_eq!(transliterate("ἱερός", false), "hieros");
+        assert_eq!(transliterate("ῥήτωρ", false), "rhetor");
+        assert_eq!(transliterate("αὐτός", false), "autos");
+        // Rough breathing on a diphthong is marked over its second vowel.
+        assert_eq!(transliterate("αὑτός", false), "hautos");
+    }
+
+    #[test]
+    fn test_rough_breathing_preserves_capitalization() {
+        // The breathing lands on "H", so the vowel itself stays lowercase.
+        assert_eq!(transliterate("Ἅγιος", false), "Hagios");
+        assert_eq!(transliterate("Ἡρώδης", false), "Herodes");
+    }
+
+    #[test]
+    fn test_keep_accents_flag() {
+        assert_eq!(transliterate("λόγος", false), "logos");
+        assert_eq!(transliterate("λόγος", true), "lo\u{0301}gos");
+        assert_eq!(transliterate("ζῆλος", true), "ze\u{0304}los");
+    }
+}